@@ -0,0 +1,180 @@
+use azure_core::{headers::AUTHORIZATION, Method, Request};
+use crate::{
+    clients::ServiceType,
+    cloud_location::{BearerTokenContext, CloudLocation},
+    StorageCredentials,
+};
+use url::Url;
+
+/// A client for the table service, built from a [`CloudLocation`].
+///
+/// A `TokenCredential` is exchanged for a cached, auto-refreshing bearer token via
+/// [`authorization_header`](TableClient::authorization_header), so a `TableClient` built from a
+/// `CloudLocation` with no account key at all (managed identity, workload identity, `az login`)
+/// can still authenticate. For an account key or SAS the client attaches no authorization;
+/// callers sign those requests themselves (see [`prepare_request`](TableClient::prepare_request)
+/// and [`CloudLocation::generate_sas`]).
+pub struct TableClient {
+    cloud_location: CloudLocation,
+    bearer_token_context: Option<BearerTokenContext>,
+}
+
+impl TableClient {
+    pub fn new(cloud_location: CloudLocation) -> Self {
+        let bearer_token_context = cloud_location.bearer_token_context();
+        Self {
+            cloud_location,
+            bearer_token_context,
+        }
+    }
+
+    /// The base URL for the table service at this client's cloud location.
+    pub fn url(&self) -> azure_core::Result<Url> {
+        self.cloud_location.url(ServiceType::Table)
+    }
+
+    pub fn credentials(&self) -> &StorageCredentials {
+        self.cloud_location.credentials()
+    }
+
+    /// The `Authorization` header value for an outgoing request, when this client is backed by a
+    /// `TokenCredential`.
+    ///
+    /// Returns `None` when the location instead expects per-request signing (account key or
+    /// SAS); callers should fall back to their usual signing path in that case.
+    pub async fn authorization_header(&self) -> azure_core::Result<Option<String>> {
+        match &self.bearer_token_context {
+            Some(context) => Ok(Some(context.authorization_header().await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Builds a request against `url`, attaching the `Authorization: Bearer` header when this
+    /// client is backed by a `TokenCredential`.
+    ///
+    /// When it isn't (account key or SAS credentials), the request is returned unauthorized;
+    /// callers are expected to sign it themselves (e.g. by appending a [`CloudLocation::generate_sas`]
+    /// query string to `url` before calling this, or by relying on the pipeline's shared-key
+    /// signing policy).
+    pub async fn prepare_request(&self, method: Method, url: Url) -> azure_core::Result<Request> {
+        let mut request = Request::new(url, method);
+        if let Some(header) = self.authorization_header().await? {
+            request.insert_header(AUTHORIZATION, header);
+        }
+        Ok(request)
+    }
+
+    /// Builds the `GET Tables` request that lists the tables in this account.
+    ///
+    /// This is the Table service's simplest operation, issued against the service root itself,
+    /// and is the first real call site for [`prepare_request`](TableClient::prepare_request): the
+    /// rest of the table operation surface declared in `table::mod` (entity queries, batches,
+    /// ...) doesn't exist yet in this crate, so nothing else constructs requests through a
+    /// `TableClient` yet.
+    pub async fn list_tables_request(&self) -> azure_core::Result<Request> {
+        let mut url = self.url()?;
+        url.path_segments_mut()
+            .map_err(|_| {
+                azure_core::Error::with_message(azure_core::error::ErrorKind::DataConversion, || {
+                    "table service URL cannot be a base"
+                })
+            })?
+            .push("Tables");
+
+        self.prepare_request(Method::Get, url).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::auth::{AccessToken, TokenCredential};
+    use std::sync::Arc;
+    use time::OffsetDateTime;
+
+    #[derive(Debug)]
+    struct FakeCredential;
+
+    #[async_trait::async_trait]
+    impl TokenCredential for FakeCredential {
+        async fn get_token(&self, _scopes: &[&str]) -> azure_core::Result<AccessToken> {
+            Ok(AccessToken::new(
+                "fake-token".to_string(),
+                OffsetDateTime::now_utc() + time::Duration::hours(1),
+            ))
+        }
+
+        async fn clear_cache(&self) -> azure_core::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_authorization_header_uses_token_credential() -> azure_core::Result<()> {
+        let cloud_location = CloudLocation::Public {
+            account: "test".to_string(),
+            credentials: StorageCredentials::TokenCredential(Arc::new(FakeCredential)),
+        };
+        let client = TableClient::new(cloud_location);
+
+        assert_eq!(
+            client.authorization_header().await?,
+            Some("Bearer fake-token".to_string())
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_authorization_header_none_for_key_credentials() -> azure_core::Result<()> {
+        let cloud_location = CloudLocation::Public {
+            account: "test".to_string(),
+            credentials: StorageCredentials::Key("test".to_string(), "key".to_string()),
+        };
+        let client = TableClient::new(cloud_location);
+
+        assert_eq!(client.authorization_header().await?, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_prepare_request_attaches_bearer_header() -> azure_core::Result<()> {
+        let cloud_location = CloudLocation::Public {
+            account: "test".to_string(),
+            credentials: StorageCredentials::TokenCredential(Arc::new(FakeCredential)),
+        };
+        let client = TableClient::new(cloud_location);
+
+        let request = client
+            .prepare_request(Method::Get, client.url()?)
+            .await?;
+
+        assert_eq!(
+            request.headers().get_as_str(&AUTHORIZATION),
+            Some("Bearer fake-token")
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_tables_request_is_authorized_and_targets_tables_resource(
+    ) -> azure_core::Result<()> {
+        let cloud_location = CloudLocation::Public {
+            account: "test".to_string(),
+            credentials: StorageCredentials::TokenCredential(Arc::new(FakeCredential)),
+        };
+        let client = TableClient::new(cloud_location);
+
+        let request = client.list_tables_request().await?;
+
+        assert_eq!(request.url().path(), "/Tables");
+        assert_eq!(
+            request.headers().get_as_str(&AUTHORIZATION),
+            Some("Bearer fake-token")
+        );
+
+        Ok(())
+    }
+}